@@ -1,17 +1,31 @@
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::fs::File;
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use arrow::{
     array::{
-        Array, BooleanArray, Float64Array, Int64Array, NullArray, StringArray,
-        TimestampMillisecondArray,
+        Array, BooleanArray, Date32Array, Float64Array, Int64Array, NullArray, StringArray,
+        StructArray, TimestampMillisecondArray,
     },
-    datatypes::{DataType as ArrowDataType, Schema},
-    record_batch::RecordBatch,
+    compute::{cast_with_options, CastOptions},
+    datatypes::{DataType as ArrowDataType, Schema, SchemaRef},
+    error::ArrowError,
+    ffi::to_ffi,
+    ffi_stream::FFI_ArrowArrayStream,
+    ipc::{writer::IpcWriteOptions, writer::StreamWriter, CompressionType},
+    record_batch::{RecordBatch, RecordBatchReader},
 };
 use calamine::{DataType as CalDataType, Range};
+use chrono::NaiveDate;
+use parquet::{
+    arrow::ArrowWriter,
+    basic::Compression,
+    file::properties::WriterProperties,
+};
 
-use pyo3::{pyclass, pymethods, PyObject, Python};
+use pyo3::{pyclass, pymethods, types::PyBytes, types::PyCapsule, PyObject, Python, ToPyObject};
 
 use crate::utils::arrow::record_batch_to_pybytes;
 
@@ -137,43 +151,360 @@ fn create_date_array(
     )))
 }
 
+/// Exports a [`RecordBatch`] as the `(schema_capsule, array_capsule)` pair
+/// mandated by the Arrow C Data Interface. Each capsule owns its exported FFI
+/// struct and releases it from its destructor, so the Python consumer can
+/// adopt the buffers with zero copy instead of re-parsing IPC.
+fn export_record_batch(py: Python<'_>, rb: RecordBatch) -> Result<PyObject> {
+    // `StructArray::from(RecordBatch)` panics on a column-less batch, so reject
+    // that edge up front rather than exporting an unusable FFI struct.
+    if rb.num_columns() == 0 {
+        return Err(anyhow!(
+            "Cannot export a sheet with zero columns over the Arrow C Data Interface"
+        ));
+    }
+    let data = StructArray::from(rb).into_data();
+
+    // `to_ffi` borrows the array data, shares its buffers and hands back the two FFI structs, each
+    // carrying the release callbacks that free the exported buffers.
+    let (ffi_array, ffi_schema) =
+        to_ffi(&data).context("Could not export Arrow array over the C Data Interface")?;
+
+    let schema_capsule = PyCapsule::new_with_destructor(
+        py,
+        ffi_schema,
+        Some(CString::new("arrow_schema").unwrap()),
+        |schema, _| drop(schema),
+    )?;
+    let array_capsule = PyCapsule::new_with_destructor(
+        py,
+        ffi_array,
+        Some(CString::new("arrow_array").unwrap()),
+        |array, _| drop(array),
+    )?;
+
+    Ok((schema_capsule, array_capsule).to_object(py))
+}
+
+/// Default window size used by [`ExcelSheet::__arrow_c_stream__`] when the
+/// caller does not request a specific one.
+const DEFAULT_BATCH_SIZE: usize = 1_000_000;
+
+/// Reads one column from the sheet into the native Arrow array matching
+/// `data_type`, using the same per-type helpers for every code path.
+fn create_native_array(
+    data_type: &ArrowDataType,
+    data: &Range<CalDataType>,
+    col: usize,
+    start: usize,
+    end: usize,
+) -> Arc<dyn Array> {
+    match data_type {
+        ArrowDataType::Boolean => create_boolean_array(data, col, start, end),
+        ArrowDataType::Int64 => create_int_array(data, col, start, end),
+        ArrowDataType::Float64 => create_float_array(data, col, start, end),
+        ArrowDataType::Utf8 => create_string_array(data, col, start, end),
+        ArrowDataType::Date64 => create_date_array(data, col, start, end),
+        ArrowDataType::Null => Arc::new(NullArray::new(end - start)),
+        _ => unreachable!(),
+    }
+}
+
+/// Builds the [`RecordBatch`] covering the `start..end` row window of the
+/// sheet. The column construction reuses the same `create_*` helpers as the
+/// whole-sheet conversion, only with per-window bounds, so every window carries
+/// the same schema (the one [`ExcelSheet::batch_schema`] returns).
+fn record_batch_for_range(sheet: &ExcelSheet, start: usize, end: usize) -> Result<RecordBatch> {
+    let iter = sheet
+        .schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(col_idx, field)| {
+            (
+                field.name(),
+                create_native_array(field.data_type(), sheet.data(), col_idx, start, end),
+            )
+        });
+    RecordBatch::try_from_iter(iter)
+        .with_context(|| format!("Could not convert sheet {} to RecordBatch", sheet.name))
+}
+
 impl TryFrom<&ExcelSheet> for RecordBatch {
     type Error = anyhow::Error;
 
     fn try_from(value: &ExcelSheet) -> Result<Self, Self::Error> {
-        let offset = value.offset();
-        let height = value.data().height();
-        let iter = value
-            .schema()
-            .fields()
-            .iter()
-            .enumerate()
-            .map(|(col_idx, field)| {
-                (
-                    field.name(),
-                    match field.data_type() {
-                        ArrowDataType::Boolean => {
-                            create_boolean_array(value.data(), col_idx, offset, height)
-                        }
-                        ArrowDataType::Int64 => {
-                            create_int_array(value.data(), col_idx, offset, height)
-                        }
-                        ArrowDataType::Float64 => {
-                            create_float_array(value.data(), col_idx, offset, height)
-                        }
-                        ArrowDataType::Utf8 => {
-                            create_string_array(value.data(), col_idx, offset, height)
-                        }
-                        ArrowDataType::Date64 => {
-                            create_date_array(value.data(), col_idx, offset, height)
-                        }
-                        ArrowDataType::Null => Arc::new(NullArray::new(height - offset)),
-                        _ => unreachable!(),
-                    },
-                )
-            });
-        RecordBatch::try_from_iter(iter)
-            .with_context(|| format!("Could not convert sheet {} to RecordBatch", value.name))
+        record_batch_for_range(value, value.offset(), value.data().height())
+    }
+}
+
+/// A parsed user-supplied dtype override: the [`ArrowDataType`] that the
+/// override path casts a column to.
+///
+/// A target parsed from a date spelling may carry an optional `chrono` format
+/// string, e.g. `date64(%m/%d/%Y)`, which drives a parse-then-cast path so
+/// non-ISO date strings coerce instead of silently nulling under arrow's
+/// default-format cast.
+struct DtypeOverride {
+    target: ArrowDataType,
+    date_format: Option<String>,
+}
+
+fn parse_arrow_dtype(dtype: &str) -> Result<DtypeOverride> {
+    let lower = dtype.to_ascii_lowercase();
+    // Date spellings may carry a `(format)` suffix; parse them before the
+    // case-folded match so the format string keeps its original case.
+    if lower.starts_with("date") {
+        return parse_date_dtype(dtype);
+    }
+    let target = match lower.as_str() {
+        "bool" | "boolean" => ArrowDataType::Boolean,
+        "int" | "int64" => ArrowDataType::Int64,
+        "float" | "float64" | "double" => ArrowDataType::Float64,
+        "str" | "string" | "utf8" => ArrowDataType::Utf8,
+        // Accept an optional `(precision, scale)`, e.g. `decimal128(38, 2)` for
+        // currency columns. Bare `decimal` keeps the scale-0 integer default.
+        "decimal" | "decimal128" => ArrowDataType::Decimal128(38, 0),
+        other if other.starts_with("decimal") => parse_decimal_dtype(other)?,
+        other => return Err(anyhow!("Unsupported dtype override `{other}`")),
+    };
+    Ok(DtypeOverride {
+        target,
+        date_format: None,
+    })
+}
+
+/// Parses a `date64`/`date32` override with an optional `chrono` format in
+/// parentheses, e.g. `date64(%m/%d/%Y)`. The format (when present) keeps its
+/// original case so the strftime specifiers survive.
+fn parse_date_dtype(dtype: &str) -> Result<DtypeOverride> {
+    let (name, date_format) = match dtype.split_once('(') {
+        Some((name, rest)) => {
+            let format = rest
+                .strip_suffix(')')
+                .ok_or_else(|| anyhow!("Unsupported dtype override `{dtype}`"))?;
+            (name.trim(), Some(format.to_owned()))
+        }
+        None => (dtype, None),
+    };
+    let target = match name.to_ascii_lowercase().as_str() {
+        "date" | "date64" => ArrowDataType::Date64,
+        "date32" => ArrowDataType::Date32,
+        other => return Err(anyhow!("Unsupported dtype override `{other}`")),
+    };
+    Ok(DtypeOverride {
+        target,
+        date_format,
+    })
+}
+
+/// Parses a native Utf8 column into a [`Date32Array`] using the supplied
+/// `chrono` format, leaving unparsable cells null. The caller then casts the
+/// result to the requested date type, so custom date spellings coerce rather
+/// than silently nulling under arrow's ISO-only default cast.
+fn parse_date_column(array: &Arc<dyn Array>, format: &str, column: &str) -> Result<Arc<dyn Array>> {
+    let strings = array.as_any().downcast_ref::<StringArray>().ok_or_else(|| {
+        anyhow!("Column `{column}` must be a string column to apply a date format override")
+    })?;
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).expect("1970-01-01 is a valid date");
+    let days = strings.iter().map(|cell| {
+        cell.and_then(|value| NaiveDate::parse_from_str(value, format).ok())
+            .map(|date| (date - epoch).num_days() as i32)
+    });
+    Ok(Arc::new(Date32Array::from_iter(days)))
+}
+
+/// Parses a `decimal128(precision, scale)` (or `decimal(..)`) spelling into a
+/// [`ArrowDataType::Decimal128`], so fractional values such as cents survive
+/// the cast instead of being truncated by a hardcoded scale of 0.
+fn parse_decimal_dtype(dtype: &str) -> Result<ArrowDataType> {
+    let args = dtype
+        .split_once('(')
+        .and_then(|(_, rest)| rest.strip_suffix(')'))
+        .ok_or_else(|| anyhow!("Unsupported dtype override `{dtype}`"))?;
+    let mut parts = args.split(',').map(|part| part.trim());
+    let precision = parts
+        .next()
+        .and_then(|p| p.parse::<u8>().ok())
+        .ok_or_else(|| anyhow!("Invalid decimal precision in `{dtype}`"))?;
+    let scale = parts
+        .next()
+        .and_then(|s| s.parse::<i8>().ok())
+        .ok_or_else(|| anyhow!("Invalid decimal scale in `{dtype}`"))?;
+    Ok(ArrowDataType::Decimal128(precision, scale))
+}
+
+/// Builds a [`RecordBatch`] where the columns named in `overrides` are coerced
+/// to the requested [`ArrowDataType`] via [`cast_with_options`] instead of
+/// relying solely on calamine's inference. Cells that cannot be coerced become
+/// nulls (`safe` cast); an outright incompatible cast returns an error naming
+/// the offending column and target type.
+fn record_batch_with_overrides(
+    sheet: &ExcelSheet,
+    overrides: &HashMap<String, DtypeOverride>,
+) -> Result<RecordBatch> {
+    // Every override must name an existing column; a typo'd key would otherwise
+    // be silently dropped, so surface it as an error naming the unmatched keys.
+    let unmatched: Vec<&str> = overrides
+        .keys()
+        .map(String::as_str)
+        .filter(|name| sheet.schema().field_with_name(name).is_err())
+        .collect();
+    if !unmatched.is_empty() {
+        return Err(anyhow!(
+            "dtype override(s) for unknown column(s): {}",
+            unmatched.join(", ")
+        ));
+    }
+
+    let offset = sheet.offset();
+    let height = sheet.data().height();
+    let cast_options = CastOptions { safe: true };
+
+    let mut columns: Vec<(String, Arc<dyn Array>)> =
+        Vec::with_capacity(sheet.schema().fields().len());
+    for (col_idx, field) in sheet.schema().fields().iter().enumerate() {
+        let native = create_native_array(field.data_type(), sheet.data(), col_idx, offset, height);
+        let array = match overrides.get(field.name()) {
+            Some(override_) => {
+                // A supplied date format turns the native Utf8 column into a
+                // real date array first; otherwise rely on arrow's cast.
+                let prepared = match &override_.date_format {
+                    Some(format) => parse_date_column(&native, format, field.name())?,
+                    None => native,
+                };
+                if prepared.data_type() == &override_.target {
+                    prepared
+                } else {
+                    cast_with_options(&prepared, &override_.target, &cast_options).with_context(
+                        || {
+                            format!(
+                                "Could not coerce column `{}` to {:?}",
+                                field.name(),
+                                override_.target
+                            )
+                        },
+                    )?
+                }
+            }
+            None => native,
+        };
+        columns.push((field.name().to_owned(), array));
+    }
+
+    RecordBatch::try_from_iter(columns)
+        .with_context(|| format!("Could not convert sheet {} to RecordBatch", sheet.name))
+}
+
+impl ExcelSheet {
+    /// Slices `offset..height` into `batch_size`-row windows, building one
+    /// [`RecordBatch`] per window. The final window covers the
+    /// `(height - offset) % batch_size` remainder when that is not zero.
+    fn chunked_batches(&self, batch_size: usize) -> Result<Vec<RecordBatch>> {
+        let batch_size = batch_size.max(1);
+        let height = self.data().height();
+        let mut batches = Vec::new();
+        let mut start = self.offset();
+        while start < height {
+            let end = (start + batch_size).min(height);
+            batches.push(record_batch_for_range(self, start, end)?);
+            start = end;
+        }
+        Ok(batches)
+    }
+
+    /// Returns the [`Schema`] actually carried by the produced batches, which
+    /// can differ from [`ExcelSheet::schema`] (e.g. date columns surface as
+    /// `Timestamp(Millisecond)` rather than `Date64`). When the sheet has no
+    /// rows, an empty-range batch is built purely to infer the schema so the
+    /// result still matches what `to_arrow` would emit.
+    fn batch_schema(&self, batches: &[RecordBatch]) -> Result<SchemaRef> {
+        match batches.first() {
+            Some(batch) => Ok(batch.schema()),
+            None => {
+                let offset = self.offset();
+                Ok(record_batch_for_range(self, offset, offset)?.schema())
+            }
+        }
+    }
+}
+
+/// Maps a user-supplied compression name to an Arrow IPC [`CompressionType`].
+/// `None` (the default) keeps the bytes uncompressed to preserve current
+/// behavior.
+fn parse_ipc_compression(compression: Option<&str>) -> Result<Option<CompressionType>> {
+    match compression.map(str::to_ascii_uppercase).as_deref() {
+        None | Some("UNCOMPRESSED") => Ok(None),
+        Some("ZSTD") => Ok(Some(CompressionType::ZSTD)),
+        Some("LZ4") | Some("LZ4_FRAME") => Ok(Some(CompressionType::LZ4_FRAME)),
+        Some(other) => Err(anyhow!(
+            "Unsupported IPC compression `{other}`, expected ZSTD or LZ4"
+        )),
+    }
+}
+
+/// Serializes a [`RecordBatch`] to Arrow IPC stream bytes using the given
+/// write options, which carry the optional body compression.
+fn record_batch_to_compressed_pybytes(
+    py: Python<'_>,
+    rb: &RecordBatch,
+    options: IpcWriteOptions,
+) -> Result<PyObject> {
+    let mut buf = Vec::new();
+    {
+        let mut writer = StreamWriter::try_new_with_options(&mut buf, &rb.schema(), options)
+            .context("Could not create IPC stream writer")?;
+        writer.write(rb).context("Could not write RecordBatch to IPC")?;
+        writer.finish().context("Could not finish IPC stream")?;
+    }
+    Ok(PyBytes::new(py, &buf).into())
+}
+
+/// Maps a user-supplied compression name to a parquet [`Compression`] codec.
+fn parse_compression(compression: Option<&str>) -> Result<Compression> {
+    match compression.map(str::to_ascii_uppercase).as_deref() {
+        None | Some("UNCOMPRESSED") => Ok(Compression::UNCOMPRESSED),
+        Some("SNAPPY") => Ok(Compression::SNAPPY),
+        Some("ZSTD") => Ok(Compression::ZSTD),
+        Some("LZ4") => Ok(Compression::LZ4),
+        Some(other) => Err(anyhow!(
+            "Unsupported parquet compression `{other}`, expected one of SNAPPY, ZSTD, LZ4"
+        )),
+    }
+}
+
+/// Exports a chunked [`RecordBatchReader`] as a PyCapsule named
+/// `"arrow_array_stream"` following the Arrow C Stream interface, so consumers
+/// can iterate the windows lazily.
+fn export_stream(py: Python<'_>, reader: ChunkedBatchReader) -> Result<PyObject> {
+    let stream = FFI_ArrowArrayStream::new(Box::new(reader));
+    let capsule = PyCapsule::new_with_destructor(
+        py,
+        stream,
+        Some(CString::new("arrow_array_stream").unwrap()),
+        |stream, _| drop(stream),
+    )?;
+    Ok(capsule.to_object(py))
+}
+
+/// A [`RecordBatchReader`] that yields one batch per fixed-size row window of a
+/// sheet. Every batch carries the identical [`Schema`] so the stream is valid.
+struct ChunkedBatchReader {
+    schema: SchemaRef,
+    batches: std::vec::IntoIter<RecordBatch>,
+}
+
+impl Iterator for ChunkedBatchReader {
+    type Item = std::result::Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.batches.next().map(Ok)
+    }
+}
+
+impl RecordBatchReader for ChunkedBatchReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
     }
 }
 
@@ -204,10 +535,139 @@ impl ExcelSheet {
         self.header.header_offset()
     }
 
-    pub fn to_arrow(&self, py: Python<'_>) -> Result<PyObject> {
+    #[pyo3(signature = (compression=None))]
+    pub fn to_arrow(&self, py: Python<'_>, compression: Option<String>) -> Result<PyObject> {
         let rb = RecordBatch::try_from(self)
             .with_context(|| format!("Could not create RecordBatch from sheet {}", self.name))?;
-        record_batch_to_pybytes(py, &rb).map(|pybytes| pybytes.into())
+        match parse_ipc_compression(compression.as_deref())? {
+            // Default path keeps the historical uncompressed serialization.
+            None => record_batch_to_pybytes(py, &rb).map(|pybytes| pybytes.into()),
+            Some(codec) => {
+                let options = IpcWriteOptions::default()
+                    .try_with_compression(Some(codec))
+                    .context("Could not enable IPC body compression")?;
+                record_batch_to_compressed_pybytes(py, &rb, options)
+            }
+        }
+    }
+
+    /// Exports the sheet through the Arrow C Data Interface as a
+    /// `(schema_capsule, array_capsule)` pair, letting Python adopt the batch
+    /// with zero copy via the PyCapsule protocol.
+    #[pyo3(signature = (_requested_schema=None))]
+    pub fn __arrow_c_array__(
+        &self,
+        py: Python<'_>,
+        _requested_schema: Option<PyObject>,
+    ) -> Result<PyObject> {
+        let rb = RecordBatch::try_from(self)
+            .with_context(|| format!("Could not create RecordBatch from sheet {}", self.name))?;
+        export_record_batch(py, rb)
+    }
+
+    /// Exports the sheet as an Arrow C Stream of `batch_size`-row windows,
+    /// letting Python adopt it as an `ArrowArrayStreamReader` and iterate the
+    /// batches one at a time. All windows are built before the stream is
+    /// returned, so the whole sheet is held in memory as those batches.
+    #[pyo3(signature = (batch_size=None, _requested_schema=None))]
+    pub fn __arrow_c_stream__(
+        &self,
+        py: Python<'_>,
+        batch_size: Option<usize>,
+        _requested_schema: Option<PyObject>,
+    ) -> Result<PyObject> {
+        let batches = self.chunked_batches(batch_size.unwrap_or(DEFAULT_BATCH_SIZE))?;
+        let schema = self.batch_schema(&batches)?;
+        // Reject a column-less stream for parity with `__arrow_c_array__`, which
+        // cannot export one because `StructArray::from` panics on zero columns.
+        if schema.fields().is_empty() {
+            return Err(anyhow!(
+                "Cannot export a sheet with zero columns over the Arrow C Stream interface"
+            ));
+        }
+        let reader = ChunkedBatchReader {
+            schema,
+            batches: batches.into_iter(),
+        };
+        export_stream(py, reader)
+    }
+
+    /// Serializes the sheet to Arrow IPC bytes, overriding the inferred type of
+    /// the named columns. Each override column is read with its nearest native
+    /// reader and then cast to the requested type; uncoercible cells become
+    /// nulls and an incompatible cast errors out naming the column. A date
+    /// override may carry a `chrono` format, e.g. `date64(%m/%d/%Y)`, to parse
+    /// non-ISO date strings.
+    #[pyo3(signature = (dtypes, compression=None))]
+    pub fn to_arrow_with_dtypes(
+        &self,
+        py: Python<'_>,
+        dtypes: HashMap<String, String>,
+        compression: Option<String>,
+    ) -> Result<PyObject> {
+        let overrides = dtypes
+            .into_iter()
+            .map(|(col, dtype)| {
+                parse_arrow_dtype(&dtype)
+                    .with_context(|| format!("Invalid dtype override for column `{col}`"))
+                    .map(|parsed| (col, parsed))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        let rb = record_batch_with_overrides(self, &overrides)?;
+        match parse_ipc_compression(compression.as_deref())? {
+            None => record_batch_to_pybytes(py, &rb).map(|pybytes| pybytes.into()),
+            Some(codec) => {
+                let options = IpcWriteOptions::default()
+                    .try_with_compression(Some(codec))
+                    .context("Could not enable IPC body compression")?;
+                record_batch_to_compressed_pybytes(py, &rb, options)
+            }
+        }
+    }
+
+    /// Writes the sheet straight to a Parquet file, streaming the chunked
+    /// batches through an [`ArrowWriter`] so multi-gigabyte sheets convert
+    /// without holding a single giant [`RecordBatch`] in RAM.
+    #[pyo3(signature = (path, compression=None, row_group_size=None))]
+    pub fn to_parquet(
+        &self,
+        path: String,
+        compression: Option<String>,
+        row_group_size: Option<usize>,
+    ) -> Result<()> {
+        let mut props = WriterProperties::builder()
+            .set_compression(parse_compression(compression.as_deref())?);
+        if let Some(row_group_size) = row_group_size {
+            props = props.set_max_row_group_size(row_group_size);
+        }
+
+        let batch_size = row_group_size.unwrap_or(DEFAULT_BATCH_SIZE).max(1);
+        // Derive the writer schema from an actual (empty-range) batch: the
+        // batches materialize date columns as `Timestamp(Millisecond)`, so the
+        // `Date64` typing in `self.schema()` would make the column writer panic.
+        let offset = self.offset();
+        let schema = record_batch_for_range(self, offset, offset)?.schema();
+
+        let file = File::create(&path)
+            .with_context(|| format!("Could not create parquet file at {path}"))?;
+        let mut writer = ArrowWriter::try_new(file, schema, Some(props.build()))
+            .with_context(|| format!("Could not open parquet writer for sheet {}", self.name))?;
+
+        // Build and write one window at a time so only one batch is alive.
+        let height = self.data().height();
+        let mut start = offset;
+        while start < height {
+            let end = (start + batch_size).min(height);
+            writer
+                .write(&record_batch_for_range(self, start, end)?)
+                .with_context(|| format!("Could not write sheet {} to parquet", self.name))?;
+            start = end;
+        }
+        writer
+            .close()
+            .with_context(|| format!("Could not finalize parquet file for sheet {}", self.name))?;
+        Ok(())
     }
 
     pub fn __repr__(&self) -> String {